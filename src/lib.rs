@@ -13,8 +13,6 @@ use alloc::string::{String, ToString};
 
 use core::fmt;
 use core::str::FromStr;
-use lazy_static::lazy_static;
-use regex::Regex;
 
 /// Represents a parsed Docker image reference.
 ///
@@ -43,7 +41,7 @@ pub struct DockerImage {
     /// The optional version tag.
     pub tag: Option<String>,
     /// The optional content digest (e.g., `sha256:<64-hex-digest>`).
-    pub digest: Option<String>,
+    pub digest: Option<Digest>,
 }
 
 impl fmt::Display for DockerImage {
@@ -78,18 +76,78 @@ impl fmt::Display for DockerImage {
 pub enum DockerImageError {
     /// Indicates that the Docker image string has an invalid format.
     InvalidFormat,
+    /// Indicates that a digest was present but malformed, e.g. an unsupported
+    /// algorithm or a hex-encoded hash of the wrong length.
+    InvalidDigest,
 }
 
 impl fmt::Display for DockerImageError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             DockerImageError::InvalidFormat => write!(f, "Invalid Docker image format"),
+            DockerImageError::InvalidDigest => write!(f, "Invalid digest format"),
         }
     }
 }
 
 impl core::error::Error for DockerImageError {}
 
+/// A structured content digest identifying an image by the hash of its manifest,
+/// e.g. `sha256:<64-hex-chars>` or `sha512:<128-hex-chars>`.
+///
+/// # Examples
+/// ```
+/// use docker_image::Digest;
+///
+/// let digest: Digest = "sha256:45b23dee08af5e43a7fea6c4cf9c25ccf269ee113168c19722f87876677c5cb2".parse().unwrap();
+/// assert_eq!(digest.algorithm, "sha256");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Digest {
+    /// The hashing algorithm, e.g. `sha256` or `sha512`.
+    pub algorithm: String,
+    /// The hex-encoded hash value.
+    pub encoded: String,
+}
+
+impl fmt::Display for Digest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.algorithm, self.encoded)
+    }
+}
+
+impl FromStr for Digest {
+    type Err = DockerImageError;
+
+    /// Parses a `algorithm:hex` digest, validating the algorithm token and the
+    /// hex-encoded length expected for known algorithms (`sha256` &rarr; 64 hex
+    /// chars, `sha512` &rarr; 128 hex chars), falling back to a permissive
+    /// minimum of 32 hex chars for other registered algorithms.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let idx = s.find(':').ok_or(DockerImageError::InvalidDigest)?;
+        let (algorithm, encoded) = (&s[..idx], &s[idx + 1..]);
+
+        if !is_digest_algorithm(algorithm) {
+            return Err(DockerImageError::InvalidDigest);
+        }
+
+        let encoded_valid = encoded.bytes().all(|b| b.is_ascii_hexdigit())
+            && match algorithm {
+                "sha256" => encoded.len() == 64,
+                "sha512" => encoded.len() == 128,
+                _ => encoded.len() >= 32,
+            };
+        if !encoded_valid {
+            return Err(DockerImageError::InvalidDigest);
+        }
+
+        Ok(Digest {
+            algorithm: algorithm.to_string(),
+            encoded: encoded.to_string(),
+        })
+    }
+}
+
 impl FromStr for DockerImage {
     type Err = DockerImageError;
 
@@ -113,28 +171,96 @@ impl FromStr for DockerImage {
     /// assert_eq!(image.digest, None);
     /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        lazy_static! {
-            static ref DOCKER_IMAGE_REGEX: Regex = Regex::new(
-                r"^(?:(?P<registry>[a-z0-9]+(?:[._-][a-z0-9]+)*\.[a-z]{2,}(?::\d+)?)/)?(?P<name>[a-z0-9]+(?:[._-][a-z0-9]+)*(?:/[a-z0-9]+(?:[._-][a-z0-9]+)*)*)(?::(?P<tag>[a-zA-Z0-9._-]+))?(?:@(?P<digest>[a-z0-9]+:[a-fA-F0-9]{64}))?$"
-            )
-            .unwrap();
+        let (before_digest, digest) = match s.find('@') {
+            Some(idx) => (&s[..idx], Some(Digest::from_str(&s[idx + 1..])?)),
+            None => (s, None),
+        };
+
+        let (registry, rest) = match before_digest.find('/') {
+            Some(idx) if is_registry(&before_digest[..idx]) => (
+                Some(before_digest[..idx].to_string()),
+                &before_digest[idx + 1..],
+            ),
+            _ => (None, before_digest),
+        };
+
+        let last_component_start = rest.rfind('/').map(|idx| idx + 1).unwrap_or(0);
+        let tail = &rest[last_component_start..];
+        let (name, tag) = match tail.find(':') {
+            Some(idx) => (
+                alloc::format!("{}{}", &rest[..last_component_start], &tail[..idx]),
+                Some(tail[idx + 1..].to_string()),
+            ),
+            None => (rest.to_string(), None),
+        };
+
+        if let Some(tag) = &tag {
+            if !is_tag(tag) {
+                return Err(DockerImageError::InvalidFormat);
+            }
         }
 
-        if let Some(captures) = DOCKER_IMAGE_REGEX.captures(s) {
-            Ok(DockerImage {
-                registry: captures.name("registry").map(|m| m.as_str().to_string()),
-                name: captures
-                    .name("name")
-                    .ok_or(DockerImageError::InvalidFormat)?
-                    .as_str()
-                    .to_string(),
-                tag: captures.name("tag").map(|m| m.as_str().to_string()),
-                digest: captures.name("digest").map(|m| m.as_str().to_string()),
-            })
-        } else {
-            Err(DockerImageError::InvalidFormat)
+        if !name.split('/').all(is_label) {
+            return Err(DockerImageError::InvalidFormat);
+        }
+
+        Ok(DockerImage {
+            registry,
+            name,
+            tag,
+            digest,
+        })
+    }
+}
+
+/// Checks whether `s` matches the `[a-z0-9]+(?:[._-][a-z0-9]+)*` component grammar
+/// shared by path segments and bare registry hosts (no port).
+fn is_label(s: &str) -> bool {
+    let mut expect_alnum = true;
+    for b in s.bytes() {
+        match b {
+            b'a'..=b'z' | b'0'..=b'9' => expect_alnum = false,
+            b'.' | b'_' | b'-' if !expect_alnum => expect_alnum = true,
+            _ => return false,
+        }
+    }
+    !s.is_empty() && !expect_alnum
+}
+
+/// Checks whether `candidate` (the path segment before the first `/`) is a valid
+/// registry host: `localhost`, a `host.with.dots`, or a `host:port` pair.
+fn is_registry(candidate: &str) -> bool {
+    match candidate.rfind(':') {
+        Some(idx) => {
+            let (host, port) = (&candidate[..idx], &candidate[idx + 1..]);
+            !port.is_empty()
+                && port.bytes().all(|b| b.is_ascii_digit())
+                && (host == "localhost" || is_label(host))
+        }
+        None => candidate == "localhost" || (candidate.contains('.') && is_label(candidate)),
+    }
+}
+
+/// Checks whether `s` matches the `[A-Za-z0-9._-]+` tag grammar.
+fn is_tag(s: &str) -> bool {
+    !s.is_empty()
+        && s.bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'_' | b'-'))
+}
+
+/// Checks whether `s` is a lowercase-alphanumeric digest algorithm token,
+/// optionally separated into parts by `+`, `.`, `_` or `-` (e.g. `sha256`,
+/// `sha512`, or a multi-part algorithm like `multihash+base58`).
+fn is_digest_algorithm(s: &str) -> bool {
+    let mut expect_alnum = true;
+    for b in s.bytes() {
+        match b {
+            b'a'..=b'z' | b'0'..=b'9' => expect_alnum = false,
+            b'+' | b'.' | b'_' | b'-' if !expect_alnum => expect_alnum = true,
+            _ => return false,
         }
     }
+    !s.is_empty() && !expect_alnum
 }
 
 impl DockerImage {
@@ -148,13 +274,282 @@ impl DockerImage {
     ///
     /// let image = DockerImage::parse("ubuntu@sha256:45b23dee08af5e43a7fea6c4cf9c25ccf269ee113168c19722f87876677c5cb2").unwrap();
     /// assert_eq!(image.name, "ubuntu");
-    /// assert_eq!(image.digest, Some("sha256:45b23dee08af5e43a7fea6c4cf9c25ccf269ee113168c19722f87876677c5cb2".to_string()));
+    /// assert_eq!(image.digest.unwrap().algorithm, "sha256");
     /// ```
     pub fn parse(image_str: &str) -> Result<Self, DockerImageError> {
         Self::from_str(image_str)
     }
 }
 
+impl DockerImage {
+    /// Produces a canonical, fully-qualified form of this image reference,
+    /// following the same resolution rules as the containerd reference parser.
+    ///
+    /// - If no registry is present, the first path segment of `name` is treated
+    ///   as the registry when it contains a `.` or `:`, or is exactly `localhost`;
+    ///   otherwise the reference is assumed to live on the default registry `docker.io`.
+    /// - When the resolved registry is `docker.io` and `name` has no namespace,
+    ///   the `library/` namespace is prepended (official images).
+    /// - When neither `tag` nor `digest` is present, the tag defaults to `latest`.
+    ///
+    /// The result always has `Some(registry)` and a fully-qualified `name`, so
+    /// `nginx`, `library/nginx` and `docker.io/library/nginx:latest` all normalize
+    /// to the same reference.
+    ///
+    /// # Examples
+    /// ```
+    /// use docker_image::DockerImage;
+    ///
+    /// let image = DockerImage::parse("nginx").unwrap().normalize();
+    /// assert_eq!(image.registry, Some("docker.io".to_string()));
+    /// assert_eq!(image.name, "library/nginx".to_string());
+    /// assert_eq!(image.tag, Some("latest".to_string()));
+    /// ```
+    pub fn normalize(&self) -> DockerImage {
+        let (registry, name) = match &self.registry {
+            Some(registry) => (registry.clone(), self.name.clone()),
+            None if self.name.contains('/') => {
+                let mut segments = self.name.splitn(2, '/');
+                let first = segments.next().unwrap_or_default();
+                if is_registry(first) {
+                    let rest = segments.next().unwrap_or_default();
+                    (first.to_string(), rest.to_string())
+                } else {
+                    ("docker.io".to_string(), self.name.clone())
+                }
+            }
+            None => ("docker.io".to_string(), self.name.clone()),
+        };
+
+        let name = if registry == "docker.io" && !name.contains('/') {
+            alloc::format!("library/{}", name)
+        } else {
+            name
+        };
+
+        let tag = if self.tag.is_none() && self.digest.is_none() {
+            Some("latest".to_string())
+        } else {
+            self.tag.clone()
+        };
+
+        DockerImage {
+            registry: Some(registry),
+            name,
+            tag,
+            digest: self.digest.clone(),
+        }
+    }
+
+    /// Parses and immediately normalizes a Docker image string.
+    ///
+    /// This is a convenience function combining [`DockerImage::parse`] and
+    /// [`DockerImage::normalize`].
+    ///
+    /// # Examples
+    /// ```
+    /// use docker_image::DockerImage;
+    ///
+    /// let image = DockerImage::parse_normalized("nginx").unwrap();
+    /// assert_eq!(image.to_string(), "docker.io/library/nginx:latest");
+    /// ```
+    pub fn parse_normalized(image_str: &str) -> Result<Self, DockerImageError> {
+        Ok(Self::parse(image_str)?.normalize())
+    }
+
+    /// Renders this reference in the short form a human would type, mirroring how
+    /// the Docker CLI prints images. This is the inverse of [`DockerImage::normalize`].
+    ///
+    /// - The registry is dropped when it is `docker.io`.
+    /// - A leading `library/` namespace is stripped when the registry is `docker.io`
+    ///   or absent; under any other registry `name` is kept as is.
+    /// - The tag is omitted when it is exactly `latest` and no digest is present.
+    ///
+    /// # Examples
+    /// ```
+    /// use docker_image::DockerImage;
+    ///
+    /// let image = DockerImage::parse("docker.io/library/nginx:latest").unwrap();
+    /// assert_eq!(image.to_familiar_string(), "nginx");
+    ///
+    /// let image = DockerImage::parse("ghcr.io/owner/app:v1").unwrap();
+    /// assert_eq!(image.to_familiar_string(), "ghcr.io/owner/app:v1");
+    /// ```
+    pub fn to_familiar_string(&self) -> String {
+        let registry = self
+            .registry
+            .as_deref()
+            .filter(|registry| *registry != "docker.io");
+
+        let name = match self.name.strip_prefix("library/") {
+            Some(rest) if registry.is_none() => rest,
+            _ => self.name.as_str(),
+        };
+
+        let mut result = String::new();
+        if let Some(registry) = registry {
+            result.push_str(registry);
+            result.push('/');
+        }
+        result.push_str(name);
+
+        if let Some(tag) = &self.tag {
+            if tag != "latest" || self.digest.is_some() {
+                result.push(':');
+                result.push_str(tag);
+            }
+        }
+
+        if let Some(digest) = &self.digest {
+            result.push('@');
+            result.push_str(&digest.to_string());
+        }
+
+        result
+    }
+
+    /// Extracts the image reference from a Dockerfile `FROM` instruction,
+    /// along with the build-stage alias if one is given via `AS <stage>`.
+    ///
+    /// Supports the `FROM [--platform=<platform>] <ref> [AS <stage>]` syntax;
+    /// the `--platform` flag is recognized and ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use docker_image::DockerImage;
+    ///
+    /// let (image, stage) = DockerImage::from_dockerfile_from_line(
+    ///     "FROM --platform=linux/amd64 docker.io/library/golang:1.22 AS builder",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(image.name, "library/golang");
+    /// assert_eq!(stage, Some("builder".to_string()));
+    /// ```
+    pub fn from_dockerfile_from_line(
+        line: &str,
+    ) -> Result<(DockerImage, Option<String>), DockerImageError> {
+        let mut tokens = line.split_whitespace();
+
+        let keyword = tokens.next().ok_or(DockerImageError::InvalidFormat)?;
+        if !keyword.eq_ignore_ascii_case("FROM") {
+            return Err(DockerImageError::InvalidFormat);
+        }
+
+        let mut reference = tokens.next().ok_or(DockerImageError::InvalidFormat)?;
+        if reference.starts_with("--platform=") {
+            reference = tokens.next().ok_or(DockerImageError::InvalidFormat)?;
+        }
+        let image = DockerImage::parse(reference)?;
+
+        let stage = match tokens.next() {
+            Some(keyword) if keyword.eq_ignore_ascii_case("AS") => {
+                Some(tokens.next().ok_or(DockerImageError::InvalidFormat)?.to_string())
+            }
+            Some(_) => return Err(DockerImageError::InvalidFormat),
+            None => None,
+        };
+
+        if tokens.next().is_some() {
+            return Err(DockerImageError::InvalidFormat);
+        }
+
+        Ok((image, stage))
+    }
+
+    /// Extracts the image reference from a compose-style `image: <ref>` line,
+    /// such as those found under a service's `image:` key, including when the
+    /// entry is a YAML sequence item (`- image: <ref>`).
+    ///
+    /// # Examples
+    /// ```
+    /// use docker_image::DockerImage;
+    ///
+    /// let image = DockerImage::from_compose_image_line("    image: nginx:latest").unwrap();
+    /// assert_eq!(image.name, "nginx");
+    /// assert_eq!(image.tag, Some("latest".to_string()));
+    /// ```
+    pub fn from_compose_image_line(line: &str) -> Result<DockerImage, DockerImageError> {
+        let trimmed = line.trim_start();
+        let trimmed = trimmed.strip_prefix('-').map(str::trim_start).unwrap_or(trimmed);
+        let value = trimmed
+            .strip_prefix("image:")
+            .ok_or(DockerImageError::InvalidFormat)?
+            .trim();
+
+        let value = if let Some(rest) = value.strip_prefix('"') {
+            rest.split('"').next().unwrap_or_default()
+        } else if let Some(rest) = value.strip_prefix('\'') {
+            rest.split('\'').next().unwrap_or_default()
+        } else {
+            value.split('#').next().unwrap_or_default().trim_end()
+        };
+
+        DockerImage::parse(value)
+    }
+
+    /// Returns the namespace portion of `name`, i.e. everything before the last
+    /// `/`, or `None` if `name` carries no namespace (e.g. `nginx`).
+    ///
+    /// # Examples
+    /// ```
+    /// use docker_image::DockerImage;
+    ///
+    /// let image = DockerImage::parse("ghcr.io/owner/app").unwrap();
+    /// assert_eq!(image.namespace(), Some("owner"));
+    /// ```
+    pub fn namespace(&self) -> Option<&str> {
+        self.name.rfind('/').map(|idx| &self.name[..idx])
+    }
+
+    /// Returns the final segment of `name`, i.e. the repository without its
+    /// namespace (e.g. `app` for `owner/app`).
+    ///
+    /// # Examples
+    /// ```
+    /// use docker_image::DockerImage;
+    ///
+    /// let image = DockerImage::parse("ghcr.io/owner/app").unwrap();
+    /// assert_eq!(image.repository(), "app");
+    /// ```
+    pub fn repository(&self) -> &str {
+        match self.name.rfind('/') {
+            Some(idx) => &self.name[idx + 1..],
+            None => &self.name,
+        }
+    }
+
+    /// Classifies this reference as a bare project, a user/org-scoped repo, or
+    /// a server-qualified repo, based on whether a registry and a namespace
+    /// are present.
+    ///
+    /// # Examples
+    /// ```
+    /// use docker_image::{DockerImage, RefKind};
+    ///
+    /// assert_eq!(DockerImage::parse("nginx").unwrap().kind(), RefKind::Official);
+    /// assert_eq!(DockerImage::parse("owner/app").unwrap().kind(), RefKind::UserScoped);
+    /// assert_eq!(DockerImage::parse("ghcr.io/owner/app").unwrap().kind(), RefKind::ServerScoped);
+    /// ```
+    pub fn kind(&self) -> RefKind {
+        match (&self.registry, self.namespace()) {
+            (Some(_), _) => RefKind::ServerScoped,
+            (None, Some(_)) => RefKind::UserScoped,
+            (None, None) => RefKind::Official,
+        }
+    }
+}
+
+/// Classification of a [`DockerImage`]'s reference shape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefKind {
+    /// A bare official project with no registry and no namespace (e.g. `nginx`).
+    Official,
+    /// An org/user-scoped repo with no registry (e.g. `owner/app`).
+    UserScoped,
+    /// A repo qualified with an explicit registry (e.g. `ghcr.io/owner/app`).
+    ServerScoped,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -241,10 +636,10 @@ mod tests {
                 registry: None,
                 name: "ubuntu".to_string(),
                 tag: None,
-                digest: Some(
-                    "sha256:45b23dee08af5e43a7fea6c4cf9c25ccf269ee113168c19722f87876677c5cb2"
-                        .to_string()
-                ),
+                digest: Some(Digest {
+                    algorithm: "sha256".to_string(),
+                    encoded: "45b23dee08af5e43a7fea6c4cf9c25ccf269ee113168c19722f87876677c5cb2".to_string(),
+                }),
             })
         );
     }
@@ -260,10 +655,10 @@ mod tests {
                 registry: None,
                 name: "ubuntu".to_string(),
                 tag: Some("latest".to_string()),
-                digest: Some(
-                    "sha256:45b23dee08af5e43a7fea6c4cf9c25ccf269ee113168c19722f87876677c5cb2"
-                        .to_string()
-                ),
+                digest: Some(Digest {
+                    algorithm: "sha256".to_string(),
+                    encoded: "45b23dee08af5e43a7fea6c4cf9c25ccf269ee113168c19722f87876677c5cb2".to_string(),
+                }),
             })
         );
     }
@@ -293,10 +688,10 @@ mod tests {
                 registry: Some("my-registry.local:5000".to_string()),
                 name: "library/image-name".to_string(),
                 tag: None,
-                digest: Some(
-                    "sha256:deadbeefcafe1234567890abcdef1234567890abcdef1234567890abcdef1234"
-                        .to_string()
-                ),
+                digest: Some(Digest {
+                    algorithm: "sha256".to_string(),
+                    encoded: "deadbeefcafe1234567890abcdef1234567890abcdef1234567890abcdef1234".to_string(),
+                }),
             })
         );
     }
@@ -304,19 +699,19 @@ mod tests {
     #[test]
     fn test_invalid_format() {
         let result = DockerImage::parse("invalid@@sha256:wrong");
-        assert_eq!(result, Err(DockerImageError::InvalidFormat));
+        assert_eq!(result, Err(DockerImageError::InvalidDigest));
     }
 
     #[test]
     fn test_invalid_characters_in_tag() {
         let result = DockerImage::parse("nginx:lat@est");
-        assert_eq!(result, Err(DockerImageError::InvalidFormat));
+        assert_eq!(result, Err(DockerImageError::InvalidDigest));
     }
 
     #[test]
     fn test_invalid_digest_format() {
         let result = DockerImage::parse("ubuntu@sha256:not-a-hex-string");
-        assert_eq!(result, Err(DockerImageError::InvalidFormat));
+        assert_eq!(result, Err(DockerImageError::InvalidDigest));
     }
 
     #[test]
@@ -374,7 +769,7 @@ mod tests {
         let result = DockerImage::parse(
             "nginx@sha256:deadbeef🚀1234567890abcdef1234567890abcdef1234567890abcdef1234",
         );
-        assert_eq!(result, Err(DockerImageError::InvalidFormat));
+        assert_eq!(result, Err(DockerImageError::InvalidDigest));
     }
 
     #[test]
@@ -407,9 +802,10 @@ mod tests {
             registry: None,
             name: "ubuntu".to_string(),
             tag: None,
-            digest: Some(
-                "sha256:deadbeef1234567890abcdef1234567890abcdef1234567890abcdef1234".to_string(),
-            ),
+            digest: Some(Digest {
+                algorithm: "sha256".to_string(),
+                encoded: "deadbeef1234567890abcdef1234567890abcdef1234567890abcdef1234".to_string(),
+            }),
         };
 
         assert_display_fmt!(
@@ -424,9 +820,10 @@ mod tests {
             registry: None,
             name: "ubuntu".to_string(),
             tag: Some("latest".to_string()),
-            digest: Some(
-                "sha256:deadbeef1234567890abcdef1234567890abcdef1234567890abcdef1234".to_string(),
-            ),
+            digest: Some(Digest {
+                algorithm: "sha256".to_string(),
+                encoded: "deadbeef1234567890abcdef1234567890abcdef1234567890abcdef1234".to_string(),
+            }),
         };
 
         assert_display_fmt!(
@@ -459,15 +856,209 @@ mod tests {
         assert_display_fmt!(image, "docker.io/library/nginx:latest");
     }
 
+    #[test]
+    fn test_normalize_trivial_name() {
+        let image = DockerImage::parse("nginx").unwrap().normalize();
+        assert_eq!(
+            image,
+            DockerImage {
+                registry: Some("docker.io".to_string()),
+                name: "library/nginx".to_string(),
+                tag: Some("latest".to_string()),
+                digest: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_normalize_namespaced_name() {
+        let image = DockerImage::parse("library/nginx").unwrap().normalize();
+        assert_eq!(
+            image,
+            DockerImage {
+                registry: Some("docker.io".to_string()),
+                name: "library/nginx".to_string(),
+                tag: Some("latest".to_string()),
+                digest: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_normalize_already_canonical() {
+        let image = DockerImage::parse("docker.io/library/nginx:latest")
+            .unwrap()
+            .normalize();
+        assert_eq!(
+            image,
+            DockerImage {
+                registry: Some("docker.io".to_string()),
+                name: "library/nginx".to_string(),
+                tag: Some("latest".to_string()),
+                digest: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_normalize_user_scoped_name() {
+        let image = DockerImage::parse("owner/app").unwrap().normalize();
+        assert_eq!(
+            image,
+            DockerImage {
+                registry: Some("docker.io".to_string()),
+                name: "owner/app".to_string(),
+                tag: Some("latest".to_string()),
+                digest: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_normalize_custom_registry() {
+        let image = DockerImage::parse("ghcr.io/owner/app:v1")
+            .unwrap()
+            .normalize();
+        assert_eq!(
+            image,
+            DockerImage {
+                registry: Some("ghcr.io".to_string()),
+                name: "owner/app".to_string(),
+                tag: Some("v1".to_string()),
+                digest: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_normalize_bare_registry_with_port() {
+        let image = DockerImage::parse("my-registry.local:5000/image-name")
+            .unwrap()
+            .normalize();
+        assert_eq!(
+            image,
+            DockerImage {
+                registry: Some("my-registry.local:5000".to_string()),
+                name: "image-name".to_string(),
+                tag: Some("latest".to_string()),
+                digest: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_normalize_dotted_bare_name_is_not_mistaken_for_a_registry() {
+        let image = DockerImage::parse("foo.bar").unwrap().normalize();
+        assert_eq!(
+            image,
+            DockerImage {
+                registry: Some("docker.io".to_string()),
+                name: "library/foo.bar".to_string(),
+                tag: Some("latest".to_string()),
+                digest: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_normalize_localhost_bare_name_is_not_mistaken_for_a_registry() {
+        let image = DockerImage::parse("localhost").unwrap().normalize();
+        assert_eq!(
+            image,
+            DockerImage {
+                registry: Some("docker.io".to_string()),
+                name: "library/localhost".to_string(),
+                tag: Some("latest".to_string()),
+                digest: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_normalize_keeps_digest_without_default_tag() {
+        let image = DockerImage::parse(
+            "ubuntu@sha256:45b23dee08af5e43a7fea6c4cf9c25ccf269ee113168c19722f87876677c5cb2",
+        )
+        .unwrap()
+        .normalize();
+        assert_eq!(
+            image,
+            DockerImage {
+                registry: Some("docker.io".to_string()),
+                name: "library/ubuntu".to_string(),
+                tag: None,
+                digest: Some(Digest {
+                    algorithm: "sha256".to_string(),
+                    encoded: "45b23dee08af5e43a7fea6c4cf9c25ccf269ee113168c19722f87876677c5cb2".to_string(),
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_normalized() {
+        let image = DockerImage::parse_normalized("nginx").unwrap();
+        assert_display_fmt!(image, "docker.io/library/nginx:latest");
+    }
+
+    #[test]
+    fn test_to_familiar_string_drops_registry_and_library_and_latest() {
+        let image = DockerImage::parse("docker.io/library/nginx:latest").unwrap();
+        assert_eq!(image.to_familiar_string(), "nginx");
+    }
+
+    #[test]
+    fn test_to_familiar_string_keeps_custom_registry() {
+        let image = DockerImage::parse("ghcr.io/owner/app:v1").unwrap();
+        assert_eq!(image.to_familiar_string(), "ghcr.io/owner/app:v1");
+    }
+
+    #[test]
+    fn test_to_familiar_string_keeps_non_latest_tag() {
+        let image = DockerImage::parse("docker.io/library/nginx:1.27").unwrap();
+        assert_eq!(image.to_familiar_string(), "nginx:1.27");
+    }
+
+    #[test]
+    fn test_to_familiar_string_keeps_latest_tag_with_digest() {
+        let image = DockerImage::parse(
+            "docker.io/library/ubuntu:latest@sha256:45b23dee08af5e43a7fea6c4cf9c25ccf269ee113168c19722f87876677c5cb2",
+        )
+        .unwrap();
+        assert_eq!(
+            image.to_familiar_string(),
+            "ubuntu:latest@sha256:45b23dee08af5e43a7fea6c4cf9c25ccf269ee113168c19722f87876677c5cb2"
+        );
+    }
+
+    #[test]
+    fn test_to_familiar_string_round_trips_through_normalize() {
+        let image = DockerImage::parse("nginx").unwrap().normalize();
+        assert_eq!(image.to_familiar_string(), "nginx");
+    }
+
+    #[test]
+    fn test_to_familiar_string_round_trips_dotted_bare_name_through_normalize() {
+        let image = DockerImage::parse("foo.bar").unwrap().normalize();
+        assert_eq!(image.to_familiar_string(), "foo.bar");
+    }
+
+    #[test]
+    fn test_to_familiar_string_round_trips_localhost_bare_name_through_normalize() {
+        let image = DockerImage::parse("localhost").unwrap().normalize();
+        assert_eq!(image.to_familiar_string(), "localhost");
+    }
+
     #[test]
     fn test_display_full_reference() {
         let image = DockerImage {
             registry: Some("my-registry.local:5000".to_string()),
             name: "library/image-name".to_string(),
             tag: Some("v1.0.0".to_string()),
-            digest: Some(
-                "sha256:deadbeef1234567890abcdef1234567890abcdef1234567890abcdef1234".to_string(),
-            ),
+            digest: Some(Digest {
+                algorithm: "sha256".to_string(),
+                encoded: "deadbeef1234567890abcdef1234567890abcdef1234567890abcdef1234".to_string(),
+            }),
         };
 
         assert_display_fmt!(
@@ -475,4 +1066,206 @@ mod tests {
             "my-registry.local:5000/library/image-name:v1.0.0@sha256:deadbeef1234567890abcdef1234567890abcdef1234567890abcdef1234"
         );
     }
+
+    #[test]
+    fn test_digest_sha512() {
+        let digest: Digest =
+            "sha512:deadbeef1234567890abcdefdeadbeef1234567890abcdefdeadbeef1234567890abcdefdeadbeef1234567890abcdefdeadbeef1234567890abcdefdeadbeef"
+                .parse()
+                .unwrap();
+        assert_eq!(digest.algorithm, "sha512");
+        assert_eq!(digest.encoded.len(), 128);
+    }
+
+    #[test]
+    fn test_digest_sha512_wrong_length_rejected() {
+        let result: Result<Digest, _> = "sha512:deadbeef".parse();
+        assert_eq!(result, Err(DockerImageError::InvalidDigest));
+    }
+
+    #[test]
+    fn test_digest_unknown_algorithm_permissive_minimum() {
+        let digest: Digest = "multihash:deadbeef1234567890abcdef12345678".parse().unwrap();
+        assert_eq!(digest.algorithm, "multihash");
+        assert_eq!(digest.encoded, "deadbeef1234567890abcdef12345678");
+    }
+
+    #[test]
+    fn test_digest_unknown_algorithm_below_minimum_rejected() {
+        let result: Result<Digest, _> = "multihash:deadbeef".parse();
+        assert_eq!(result, Err(DockerImageError::InvalidDigest));
+    }
+
+    #[test]
+    fn test_digest_missing_colon_rejected() {
+        let result: Result<Digest, _> = "sha256deadbeef".parse();
+        assert_eq!(result, Err(DockerImageError::InvalidDigest));
+    }
+
+    #[test]
+    fn test_digest_display() {
+        let digest = Digest {
+            algorithm: "sha256".to_string(),
+            encoded: "deadbeef1234567890abcdef1234567890abcdef1234567890abcdef1234".to_string(),
+        };
+        assert_display_fmt!(
+            digest,
+            "sha256:deadbeef1234567890abcdef1234567890abcdef1234567890abcdef1234"
+        );
+    }
+
+    #[test]
+    fn test_name_with_sha512_digest() {
+        let result = DockerImage::parse(
+            "ubuntu@sha512:deadbeef1234567890abcdefdeadbeef1234567890abcdefdeadbeef1234567890abcdefdeadbeef1234567890abcdefdeadbeef1234567890abcdefdeadbeef",
+        );
+        assert_eq!(
+            result,
+            Ok(DockerImage {
+                registry: None,
+                name: "ubuntu".to_string(),
+                tag: None,
+                digest: Some(Digest {
+                    algorithm: "sha512".to_string(),
+                    encoded: "deadbeef1234567890abcdefdeadbeef1234567890abcdefdeadbeef1234567890abcdefdeadbeef1234567890abcdefdeadbeef1234567890abcdefdeadbeef".to_string(),
+                }),
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_dockerfile_from_line_simple() {
+        let (image, stage) = DockerImage::from_dockerfile_from_line("FROM nginx:latest").unwrap();
+        assert_eq!(image.name, "nginx");
+        assert_eq!(image.tag, Some("latest".to_string()));
+        assert_eq!(stage, None);
+    }
+
+    #[test]
+    fn test_from_dockerfile_from_line_with_platform_and_stage() {
+        let (image, stage) = DockerImage::from_dockerfile_from_line(
+            "FROM --platform=linux/amd64 docker.io/library/golang:1.22 AS builder",
+        )
+        .unwrap();
+        assert_eq!(image.registry, Some("docker.io".to_string()));
+        assert_eq!(image.name, "library/golang");
+        assert_eq!(image.tag, Some("1.22".to_string()));
+        assert_eq!(stage, Some("builder".to_string()));
+    }
+
+    #[test]
+    fn test_from_dockerfile_from_line_is_case_insensitive() {
+        let (image, stage) = DockerImage::from_dockerfile_from_line("from nginx as web").unwrap();
+        assert_eq!(image.name, "nginx");
+        assert_eq!(stage, Some("web".to_string()));
+    }
+
+    #[test]
+    fn test_from_dockerfile_from_line_missing_keyword() {
+        let result = DockerImage::from_dockerfile_from_line("nginx:latest");
+        assert_eq!(result, Err(DockerImageError::InvalidFormat));
+    }
+
+    #[test]
+    fn test_from_dockerfile_from_line_missing_reference() {
+        let result = DockerImage::from_dockerfile_from_line("FROM");
+        assert_eq!(result, Err(DockerImageError::InvalidFormat));
+    }
+
+    #[test]
+    fn test_from_dockerfile_from_line_trailing_garbage() {
+        let result = DockerImage::from_dockerfile_from_line("FROM nginx:latest oops");
+        assert_eq!(result, Err(DockerImageError::InvalidFormat));
+    }
+
+    #[test]
+    fn test_from_compose_image_line_simple() {
+        let image = DockerImage::from_compose_image_line("    image: nginx:latest").unwrap();
+        assert_eq!(image.name, "nginx");
+        assert_eq!(image.tag, Some("latest".to_string()));
+    }
+
+    #[test]
+    fn test_from_compose_image_line_sequence_item() {
+        let image = DockerImage::from_compose_image_line("  - image: ghcr.io/owner/app:v1").unwrap();
+        assert_eq!(image.registry, Some("ghcr.io".to_string()));
+        assert_eq!(image.name, "owner/app");
+        assert_eq!(image.tag, Some("v1".to_string()));
+    }
+
+    #[test]
+    fn test_from_compose_image_line_quoted_value() {
+        let image = DockerImage::from_compose_image_line("image: \"nginx:latest\"").unwrap();
+        assert_eq!(image.name, "nginx");
+        assert_eq!(image.tag, Some("latest".to_string()));
+    }
+
+    #[test]
+    fn test_from_compose_image_line_trailing_comment() {
+        let image =
+            DockerImage::from_compose_image_line("image: nginx:1.25  # pinned per runbook")
+                .unwrap();
+        assert_eq!(image.name, "nginx");
+        assert_eq!(image.tag, Some("1.25".to_string()));
+    }
+
+    #[test]
+    fn test_from_compose_image_line_quoted_value_with_trailing_comment() {
+        let image =
+            DockerImage::from_compose_image_line("image: \"nginx:1.25\" # pinned per runbook")
+                .unwrap();
+        assert_eq!(image.name, "nginx");
+        assert_eq!(image.tag, Some("1.25".to_string()));
+    }
+
+    #[test]
+    fn test_from_compose_image_line_not_an_image_line() {
+        let result = DockerImage::from_compose_image_line("build: .");
+        assert_eq!(result, Err(DockerImageError::InvalidFormat));
+    }
+
+    #[test]
+    fn test_namespace_and_repository_bare_name() {
+        let image = DockerImage::parse("nginx").unwrap();
+        assert_eq!(image.namespace(), None);
+        assert_eq!(image.repository(), "nginx");
+    }
+
+    #[test]
+    fn test_namespace_and_repository_user_scoped() {
+        let image = DockerImage::parse("owner/app").unwrap();
+        assert_eq!(image.namespace(), Some("owner"));
+        assert_eq!(image.repository(), "app");
+    }
+
+    #[test]
+    fn test_namespace_and_repository_server_scoped() {
+        let image = DockerImage::parse("ghcr.io/owner/app").unwrap();
+        assert_eq!(image.namespace(), Some("owner"));
+        assert_eq!(image.repository(), "app");
+    }
+
+    #[test]
+    fn test_kind_official() {
+        let image = DockerImage::parse("nginx").unwrap();
+        assert_eq!(image.kind(), RefKind::Official);
+    }
+
+    #[test]
+    fn test_kind_user_scoped() {
+        let image = DockerImage::parse("owner/app").unwrap();
+        assert_eq!(image.kind(), RefKind::UserScoped);
+    }
+
+    #[test]
+    fn test_kind_server_scoped() {
+        let image = DockerImage::parse("ghcr.io/owner/app").unwrap();
+        assert_eq!(image.kind(), RefKind::ServerScoped);
+    }
+
+    #[test]
+    fn test_kind_server_scoped_with_official_namespace() {
+        let image = DockerImage::parse("docker.io/library/nginx").unwrap();
+        assert_eq!(image.kind(), RefKind::ServerScoped);
+    }
 }